@@ -1,9 +1,12 @@
-use crate::grid::Grid;
+use crate::grid::{Grid, CELL_SPACING};
 use graphics::color::BLACK;
 use graphics::Graphics;
 use opengl_graphics::GlGraphics;
 use piston::{EventSettings, Events, RenderArgs, UpdateArgs};
-use piston_window::{PistonWindow, RenderEvent, UpdateEvent};
+use piston_window::{
+    Button, Key, MouseButton, MouseCursorEvent, PistonWindow, PressEvent, ReleaseEvent,
+    RenderEvent, UpdateEvent,
+};
 
 // Codigo tomado y referenciado de : https://medium.com/@mfriedrich/get-started-with-graphics-programming-in-rust-d98c26e41e5f
 
@@ -12,22 +15,35 @@ pub(crate) struct App {
     pub(crate) x_cells: u32,
     pub(crate) y_cells: u32,
     pub(crate) grid: Grid, // Cuadrícula que representa el área de dibujo
+    /// Indica si la simulación está pausada para permitir la edición manual.
+    pub(crate) paused: bool,
+    /// Última posición conocida del cursor en píxeles.
+    cursor: [f64; 2],
+    /// Botón del ratón que se mantiene presionado mientras se dibuja, si lo hay.
+    painting: Option<MouseButton>,
 }
 
 impl App {
-    pub(crate) fn new(gl: GlGraphics, x_cells: u32, y_cells: u32) -> Self {
+    pub(crate) fn new(gl: GlGraphics, x_cells: u32, y_cells: u32, cell_size: f64, wrap: bool) -> Self {
         Self {
             gl,
             x_cells,
             y_cells,
-            grid: Grid::new(x_cells, y_cells), // Inicializa la cuadrícula con las dimensiones especificadas
+            grid: Grid::new(x_cells, y_cells, cell_size, wrap), // Inicializa la cuadrícula con las dimensiones especificadas
+            paused: true,
+            cursor: [0.0, 0.0],
+            painting: None,
         }
     }
 
     fn render(&mut self, args: &RenderArgs) {
-        self.grid.update(); // Actualiza el estado de la cuadrícula
+        // Solo avanza una generación cuando la simulación no está pausada,
+        // de modo que la edición manual ocurre sobre una cuadrícula congelada.
+        if !self.paused {
+            self.grid.update(); // Actualiza el estado de la cuadrícula
+        }
         self.gl.draw(args.viewport(), |c, g| {
-            g.clear_color(GRAY); // Limpia el fondo con color negro
+            g.clear_color(BLACK); // Limpia el fondo con color negro
 
             // Dibuja cada celda en la cuadrícula
             self.grid.cells.iter().for_each(|cell| {
@@ -36,7 +52,41 @@ impl App {
         });
     }
 
-    fn update(&mut self, args: &UpdateArgs) {}
+    fn update(&mut self, _args: &UpdateArgs) {}
+
+    /// Traduce una coordenada en píxeles al índice de la celda correspondiente.
+    ///
+    /// # Parámetros
+    /// - `pos`: Posición del cursor en píxeles `[x, y]`.
+    ///
+    /// # Retorna
+    /// El índice de la celda bajo el cursor, o `None` si cae fuera de la cuadrícula.
+    fn cell_index_at(&self, pos: [f64; 2]) -> Option<usize> {
+        let stride = self.grid.cell_size + CELL_SPACING;
+        if pos[0] < 0.0 || pos[1] < 0.0 {
+            return None;
+        }
+        let col = (pos[0] / stride) as u32;
+        let row = (pos[1] / stride) as u32;
+        if col >= self.x_cells || row >= self.y_cells {
+            return None;
+        }
+        Some((col + row * self.x_cells) as usize)
+    }
+
+    /// Pinta la celda bajo el cursor según el botón presionado: el botón
+    /// izquierdo la marca viva y el derecho la marca muerta.
+    ///
+    /// # Parámetros
+    /// - `button`: El botón del ratón que se mantiene presionado.
+    fn paint(&mut self, button: MouseButton) {
+        if let Some(idx) = self.cell_index_at(self.cursor) {
+            match button {
+                MouseButton::Right => self.grid.cells[idx].make_dead(),
+                _ => self.grid.cells[idx].make_live(),
+            }
+        }
+    }
 }
 
 pub(crate) fn run_loop(app: &mut App, w: &mut PistonWindow) {
@@ -49,5 +99,40 @@ pub(crate) fn run_loop(app: &mut App, w: &mut PistonWindow) {
         if let Some(args) = e.update_args() {
             app.update(&args);
         }
+
+        // Sigue la posición del cursor y pinta si un botón está presionado.
+        if let Some(pos) = e.mouse_cursor_args() {
+            app.cursor = pos;
+            if let Some(button) = app.painting {
+                app.paint(button);
+            }
+        }
+
+        // Presionar un botón del ratón comienza a pintar; la barra espaciadora
+        // alterna el avance de la simulación.
+        if let Some(button) = e.press_args() {
+            match button {
+                Button::Mouse(mouse_button) => {
+                    app.painting = Some(mouse_button);
+                    app.paint(mouse_button);
+                }
+                Button::Keyboard(Key::Space) => {
+                    app.paused = !app.paused;
+                }
+                Button::Keyboard(Key::S) => {
+                    // Vuelca el estado actual a texto plano (`.`/`O`) en rgol_save.txt.
+                    match std::fs::write("rgol_save.txt", app.grid.to_plaintext()) {
+                        Ok(()) => println!("Estado guardado en rgol_save.txt"),
+                        Err(e) => eprintln!("No se pudo guardar el estado: {}", e),
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Soltar el botón del ratón detiene el dibujo.
+        if let Some(Button::Mouse(_)) = e.release_args() {
+            app.painting = None;
+        }
     }
 }
@@ -1,7 +1,33 @@
+use crate::ruleset::Ruleset;
 use graphics::color::{BLACK, RED};
 use graphics::types::Color;
 use graphics::Rectangle;
 
+/// Generaciones que tarda una célula viva en alcanzar su color "maduro".
+const MATURITY: u32 = 30;
+/// Generaciones durante las cuales una célula muerta se desvanece hacia el fondo.
+const FADE: u32 = 8;
+
+/// Interpola linealmente entre dos colores RGBA.
+///
+/// # Parámetros
+/// - `from`: Color de partida (`t == 0.0`).
+/// - `to`: Color de llegada (`t == 1.0`).
+/// - `t`: Factor de interpolación en el rango `[0.0, 1.0]`.
+///
+/// # Retorna
+/// El color resultante de la interpolación.
+fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    std::array::from_fn(|i| from[i] + (to[i] - from[i]) * t)
+}
+
+/// Color brillante con el que nace una célula.
+const BORN: Color = [1.0, 1.0, 0.4, 1.0];
+/// Color de una célula que ha vivido muchas generaciones.
+const MATURE: Color = RED;
+/// Color de fondo hacia el que se desvanecen las células muertas.
+const DEAD: Color = BLACK;
+
 /// Representa una célula en la cuadrícula de la simulación.
 ///
 /// La célula puede estar viva o muerta y tiene información sobre su estado actual y próximo,
@@ -19,10 +45,10 @@ pub struct LifeCell {
     current_state: bool,
     /// El estado siguiente de la célula después de la actualización.
     next_state: bool,
-    /// Color de la célula cuando está viva.
-    alive_color: [f32; 4],
-    /// Color de la célula cuando está muerta.
-    dead_color: [f32; 4],
+    /// Generaciones que la célula lleva viva de forma continua.
+    age: u32,
+    /// Generaciones transcurridas desde que la célula murió.
+    dead_since: u32,
 }
 
 impl LifeCell {
@@ -37,13 +63,29 @@ impl LifeCell {
     pub(crate) fn new(alive: bool, corners: [f64; 4]) -> Self {
         Self {
             alive,
-            rect: Rectangle::new(if alive { RED } else { BLACK }),
+            rect: Rectangle::new(if alive { BORN } else { BLACK }),
             corners,
             neighbor_indices: vec![],
             current_state: alive,
             next_state: alive,
-            alive_color: RED,
-            dead_color: BLACK,
+            age: 0,
+            // Una célula que nunca ha vivido arranca completamente desvanecida
+            // al fondo, no como si acabara de morir.
+            dead_since: if alive { 0 } else { FADE },
+        }
+    }
+
+    /// Recalcula el color del rectángulo interpolando según la edad de la célula.
+    ///
+    /// Las células vivas transitan de `BORN` a `MATURE` a medida que envejecen;
+    /// las muertas se desvanecen desde `MATURE` hacia `DEAD` (el fondo).
+    fn refresh_color(&mut self) {
+        if self.alive {
+            let t = (self.age as f32 / MATURITY as f32).min(1.0);
+            self.rect.color = lerp_color(BORN, MATURE, t);
+        } else {
+            let t = (self.dead_since as f32 / FADE as f32).min(1.0);
+            self.rect.color = lerp_color(MATURE, DEAD, t);
         }
     }
 
@@ -60,13 +102,20 @@ impl LifeCell {
     /// Marca la célula como viva y actualiza el color del rectángulo.
     pub fn make_live(&mut self) {
         self.set_state(true);
-        self.rect.color = self.alive_color;
+        self.age = 0;
+        self.dead_since = 0;
+        self.refresh_color();
     }
 
     /// Marca la célula como muerta y actualiza el color del rectángulo.
     pub fn make_dead(&mut self) {
+        let was_alive = self.alive;
         self.set_state(false);
-        self.rect.color = self.dead_color;
+        self.age = 0;
+        // El desvanecimiento solo se reinicia en una transición real viva→muerta;
+        // las células que ya estaban muertas permanecen al color de fondo.
+        self.dead_since = if was_alive { 0 } else { FADE };
+        self.refresh_color();
     }
 
     /// Verifica si la célula necesita ser actualizada comparando su estado siguiente y actual.
@@ -89,11 +138,15 @@ impl LifeCell {
     ///
     /// # Parámetros
     /// - `live_neighbors`: Número de vecinos vivos de la célula.
-    pub fn prepare_update(&mut self, live_neighbors: u8) {
-        // Calcula el próximo estado basado en las reglas del Juego de la Vida.
-        if !(!self.current_state && live_neighbors < 3) {
-            self.next_state = (self.current_state && live_neighbors == 2) || (live_neighbors == 3);
-        }
+    /// - `ruleset`: Conjunto de reglas de nacimiento/supervivencia activo.
+    pub fn prepare_update(&mut self, live_neighbors: u8, ruleset: &Ruleset) {
+        // Consulta la regla según el estado actual y el número de vecinos vivos.
+        let n = live_neighbors as usize;
+        self.next_state = if self.current_state {
+            ruleset.survive[n]
+        } else {
+            ruleset.birth[n]
+        };
     }
 
     /// Actualiza el estado de la célula según su estado siguiente.
@@ -104,6 +157,15 @@ impl LifeCell {
             } else {
                 self.make_dead();
             }
+        } else {
+            // El estado no cambia: la célula envejece y su color avanza por el
+            // gradiente correspondiente a estar viva o muerta.
+            if self.alive {
+                self.age = self.age.saturating_add(1);
+            } else {
+                self.dead_since = self.dead_since.saturating_add(1);
+            }
+            self.refresh_color();
         }
     }
 }
@@ -0,0 +1,41 @@
+use crate::grid::CELL_SIZE;
+use clap::Parser;
+
+/// Opciones de línea de comandos para configurar la simulación.
+#[derive(Parser)]
+#[command(name = "RGoL", about = "Juego de la Vida de Conway con reglas y semillas configurables")]
+pub(crate) struct Cli {
+    /// Número de células en el eje X.
+    #[arg(long, default_value_t = 400)]
+    pub(crate) width: u32,
+    /// Número de células en el eje Y.
+    #[arg(long, default_value_t = 400)]
+    pub(crate) height: u32,
+    /// Tamaño en píxeles del lado de cada célula.
+    #[arg(long, default_value_t = CELL_SIZE)]
+    pub(crate) cell_size: f64,
+    /// Probabilidad de que una célula nazca viva al sembrar aleatoriamente.
+    #[arg(long, default_value_t = 0.50)]
+    pub(crate) density: f64,
+    /// Regla en notación B/S, por ejemplo "B3/S23".
+    #[arg(long, default_value = "B3/S23")]
+    pub(crate) rule: String,
+    /// Archivo RLE con el patrón inicial; si se indica, ignora la densidad.
+    #[arg(long)]
+    pub(crate) pattern: Option<String>,
+    /// Semilla del generador aleatorio para obtener tableros reproducibles.
+    #[arg(long)]
+    pub(crate) seed: Option<u64>,
+    /// Activa bordes toroidales (envolventes) en el cálculo de vecinos.
+    #[arg(long)]
+    pub(crate) wrap: bool,
+    /// Siembra con ruido de valor coherente en lugar del muestreo uniforme.
+    #[arg(long)]
+    pub(crate) noise: bool,
+    /// Escala del campo de ruido (valores pequeños dan manchas más grandes).
+    #[arg(long, default_value_t = 0.08)]
+    pub(crate) noise_scale: f64,
+    /// Umbral por encima del cual nace una célula al usar ruido.
+    #[arg(long, default_value_t = 0.55)]
+    pub(crate) noise_threshold: f64,
+}
@@ -1,12 +1,14 @@
 use crate::celulas::LifeCell;
+use crate::ruleset::Ruleset;
 use graphics::rectangle::rectangle_by_corners;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rayon::iter::*;
 
 /// Espaciado entre las células en la cuadrícula.
-const CELL_SPACING: f64 = 1.0;
-/// Tamaño de cada célula en la cuadrícula.
-const CELL_SIZE: f64 = 5.0;
+pub(crate) const CELL_SPACING: f64 = 1.0;
+/// Tamaño por defecto de cada célula en la cuadrícula.
+pub(crate) const CELL_SIZE: f64 = 5.0;
 
 /// Representa una cuadrícula de células para la simulación del Juego de la Vida.
 pub struct Grid {
@@ -18,6 +20,12 @@ pub struct Grid {
     pub(crate) generation: u64,
     /// Vector que contiene todas las células en la cuadrícula.
     pub(crate) cells: Vec<LifeCell>,
+    /// Conjunto de reglas que rige la transición de cada célula.
+    pub(crate) ruleset: Ruleset,
+    /// Tamaño en píxeles del lado de cada célula.
+    pub(crate) cell_size: f64,
+    /// Generador pseudoaleatorio sembrable para producir tableros reproducibles.
+    rng: StdRng,
 }
 
 impl Grid {
@@ -26,40 +34,58 @@ impl Grid {
     /// # Parámetros
     /// - `x_cells`: Número de células en el eje X.
     /// - `y_cells`: Número de células en el eje Y.
+    /// - `cell_size`: Tamaño en píxeles del lado de cada célula.
+    /// - `wrap`: Si los bordes deben comportarse como un toro envolvente.
     ///
     /// # Retorna
     /// Una nueva instancia de `Grid`.
-    pub(crate) fn new(x_cells: u32, y_cells: u32) -> Self {
+    pub(crate) fn new(x_cells: u32, y_cells: u32, cell_size: f64, wrap: bool) -> Self {
         Self {
             x_cells,
             y_cells,
             generation: 0,
-            cells: Grid::create_cell_grid(x_cells, y_cells),
+            cells: Grid::create_cell_grid(x_cells, y_cells, cell_size, wrap),
+            ruleset: Ruleset::default(),
+            cell_size,
+            rng: StdRng::seed_from_u64(0),
         }
     }
 
+    /// Resiembra el generador pseudoaleatorio para obtener tableros reproducibles.
+    ///
+    /// # Parámetros
+    /// - `seed`: Semilla que determina la secuencia aleatoria.
+    pub(crate) fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
     /// Crea la cuadrícula de células inicial.
     ///
     /// # Parámetros
     /// - `x_cells`: Número de células en el eje X.
     /// - `y_cells`: Número de células en el eje Y.
+    /// - `cell_size`: Tamaño en píxeles del lado de cada célula.
+    /// - `wrap`: Si los vecinos deben calcularse de forma envolvente (toroidal).
     ///
     /// # Retorna
     /// Un vector de células (`LifeCell`).
-    fn create_cell_grid(x_cells: u32, y_cells: u32) -> Vec<LifeCell> {
+    fn create_cell_grid(x_cells: u32, y_cells: u32, cell_size: f64, wrap: bool) -> Vec<LifeCell> {
         let mut cell_grid = Vec::with_capacity((x_cells * y_cells) as usize);
-        for x in 0..x_cells {
-            for y in 0..y_cells {
-                let top_left_x = (x as f64) * (CELL_SIZE + CELL_SPACING);
-                let top_left_y = (y as f64) * (CELL_SIZE + CELL_SPACING);
-                let bottom_right_x = top_left_x + CELL_SIZE;
-                let bottom_right_y = top_left_y + CELL_SIZE;
+        // Se itera `y` por fuera y `x` por dentro para que el índice secuencial
+        // del vector coincida con `x + y * x_cells`, la convención usada por el
+        // resto de la cuadrícula (vecinos, índices, volcado de texto).
+        for y in 0..y_cells {
+            for x in 0..x_cells {
+                let top_left_x = (x as f64) * (cell_size + CELL_SPACING);
+                let top_left_y = (y as f64) * (cell_size + CELL_SPACING);
+                let bottom_right_x = top_left_x + cell_size;
+                let bottom_right_y = top_left_y + cell_size;
                 let corners = rectangle_by_corners(top_left_x, top_left_y, bottom_right_x, bottom_right_y);
                 let cell = LifeCell::new(false, corners);
                 cell_grid.push(cell);
             }
         }
-        Grid::set_neighbors(x_cells, y_cells, &mut cell_grid);
+        Grid::set_neighbors(x_cells, y_cells, wrap, &mut cell_grid);
         cell_grid
     }
 
@@ -68,11 +94,12 @@ impl Grid {
     /// # Parámetros
     /// - `x_cells`: Número de células en el eje X.
     /// - `y_cells`: Número de células en el eje Y.
+    /// - `wrap`: Si los vecinos deben calcularse de forma envolvente (toroidal).
     /// - `cell_grid`: Vector mutable de células.
-    fn set_neighbors(x_cells: u32, y_cells: u32, cell_grid: &mut Vec<LifeCell>) {
+    fn set_neighbors(x_cells: u32, y_cells: u32, wrap: bool, cell_grid: &mut Vec<LifeCell>) {
         for x in 0..x_cells {
             for y in 0..y_cells {
-                let neighbor_idxs = Grid::get_neighbor_indices_for_cell(x, y, x_cells, y_cells);
+                let neighbor_idxs = Grid::get_neighbor_indices_for_cell(x, y, x_cells, y_cells, wrap);
                 cell_grid[(x + y * x_cells) as usize].neighbor_indices = neighbor_idxs;
             }
         }
@@ -86,9 +113,35 @@ impl Grid {
     /// - `x_cells`: Número de células en el eje X.
     /// - `y_cells`: Número de células en el eje Y.
     ///
+    /// - `wrap`: Si los bordes deben tratarse como un toro envolvente.
+    ///
     /// # Retorna
     /// Un vector de índices de vecinos.
-    fn get_neighbor_indices_for_cell(x: u32, y: u32, x_cells: u32, y_cells: u32) -> Vec<usize> {
+    fn get_neighbor_indices_for_cell(
+        x: u32,
+        y: u32,
+        x_cells: u32,
+        y_cells: u32,
+        wrap: bool,
+    ) -> Vec<usize> {
+        // En modo toroidal se toman las ocho direcciones y se envuelven los
+        // índices con `rem_euclid`, de modo que un patrón que sale por un borde
+        // reaparece por el opuesto.
+        if wrap {
+            let mut neighbor_idxs: Vec<usize> = Vec::with_capacity(8);
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    if dx == 0 && dy == 0 {
+                        continue;
+                    }
+                    let nx = (x as i64 + dx).rem_euclid(x_cells as i64);
+                    let ny = (y as i64 + dy).rem_euclid(y_cells as i64);
+                    neighbor_idxs.push((nx + ny * x_cells as i64) as usize);
+                }
+            }
+            return neighbor_idxs;
+        }
+
         let mut neighbor_idxs: Vec<usize> = vec![];
 
         let left_x: i64 = (x as i64) - 1;
@@ -138,10 +191,11 @@ impl Grid {
     pub(crate) fn update(&mut self) -> u64 {
         let alive_grid = self.cells.par_iter().map(|cell| cell.alive).collect::<Vec<bool>>();
 
+        let ruleset = &self.ruleset;
         self.cells.par_iter_mut().for_each(|cell| {
             let neighbor_idxs = cell.get_neighbor_indices();
             let live_neighbors: u8 = neighbor_idxs.iter().filter(|nidx| alive_grid[**nidx]).collect::<Vec<&usize>>().len() as u8;
-            cell.prepare_update(live_neighbors);
+            cell.prepare_update(live_neighbors, ruleset);
         });
 
         self.cells.par_iter_mut().for_each(|cell| {
@@ -170,13 +224,225 @@ impl Grid {
     pub(crate) fn randomize(&mut self, live_probability: f64) {
         self.reset();
 
-        let mut rng = rand::thread_rng();
         for x in 0..self.x_cells {
             for y in 0..self.y_cells {
-                if rng.gen::<f64>() <= live_probability {
+                if self.rng.gen::<f64>() <= live_probability {
+                    self.cells[(x + y * self.x_cells) as usize].make_live();
+                }
+            }
+        }
+    }
+
+    /// Siembra la cuadrícula con ruido de valor 2D coherente en lugar de un
+    /// muestreo uniforme, produciendo manchas y gradientes conectados.
+    ///
+    /// Para cada célula se muestrea un campo de ruido (con interpolación bilineal
+    /// suavizada sobre una retícula entera) escalando sus coordenadas por `scale`,
+    /// y la célula nace viva cuando el valor supera `threshold`.
+    ///
+    /// # Parámetros
+    /// - `scale`: Factor que controla el tamaño de las estructuras (valores
+    ///   pequeños producen manchas más grandes).
+    /// - `threshold`: Umbral en `[0.0, 1.0]` por encima del cual nace una célula.
+    /// - `seed`: Semilla que hace reproducible el campo de ruido.
+    pub(crate) fn seed_noise(&mut self, scale: f64, threshold: f64, seed: u32) {
+        self.reset();
+
+        for x in 0..self.x_cells {
+            for y in 0..self.y_cells {
+                let nx = x as f64 * scale;
+                let ny = y as f64 * scale;
+                if value_noise(nx, ny, seed) > threshold {
                     self.cells[(x + y * self.x_cells) as usize].make_live();
                 }
             }
         }
     }
+
+    /// Carga un patrón en formato Run Length Encoded (RLE) y lo coloca centrado.
+    ///
+    /// Reconoce un encabezado opcional `x = <w>, y = <h>, rule = B3/S23` y un
+    /// cuerpo de etiquetas donde un contador decimal precede a `b` (muerta),
+    /// `o` (viva) y `$` (fin de fila), terminando en `!`. Las líneas de
+    /// comentario que comienzan con `#` y los espacios en blanco se ignoran.
+    ///
+    /// # Parámetros
+    /// - `text`: Contenido del archivo RLE.
+    pub(crate) fn load_rle(&mut self, text: &str) {
+        self.reset();
+
+        // Separa el encabezado y los comentarios del cuerpo codificado.
+        let mut header_w = 0usize;
+        let mut header_h = 0usize;
+        let mut body = String::new();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('x') {
+                for field in line.split(',') {
+                    let mut kv = field.splitn(2, '=');
+                    let key = kv.next().unwrap_or("").trim();
+                    let val = kv.next().unwrap_or("").trim();
+                    match key {
+                        "x" => header_w = val.parse().unwrap_or(0),
+                        "y" => header_h = val.parse().unwrap_or(0),
+                        "rule" => {
+                            if let Ok(rule) = Ruleset::parse(val) {
+                                self.ruleset = rule;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            } else {
+                body.push_str(line);
+            }
+        }
+
+        // Decodifica el cuerpo en coordenadas vivas relativas al patrón.
+        let mut live: Vec<(usize, usize)> = vec![];
+        let (mut run, mut px, mut py, mut max_w) = (0usize, 0usize, 0usize, 0usize);
+        for ch in body.chars() {
+            match ch {
+                '0'..='9' => run = run * 10 + ch.to_digit(10).unwrap() as usize,
+                'b' | 'B' => {
+                    px += run.max(1);
+                    run = 0;
+                }
+                'o' | 'O' => {
+                    for _ in 0..run.max(1) {
+                        live.push((px, py));
+                        px += 1;
+                    }
+                    run = 0;
+                }
+                '$' => {
+                    py += run.max(1);
+                    px = 0;
+                    run = 0;
+                }
+                '!' => break,
+                _ => {}
+            }
+            max_w = max_w.max(px);
+        }
+
+        // Centra el patrón usando las dimensiones del encabezado cuando existen.
+        let width = if header_w > 0 { header_w } else { max_w };
+        let height = if header_h > 0 { header_h } else { py + 1 };
+        let offset_x = (self.x_cells as usize).saturating_sub(width) / 2;
+        let offset_y = (self.y_cells as usize).saturating_sub(height) / 2;
+
+        for (dx, dy) in live {
+            let gx = offset_x + dx;
+            let gy = offset_y + dy;
+            if gx < self.x_cells as usize && gy < self.y_cells as usize {
+                self.cells[gx + gy * self.x_cells as usize].make_live();
+            }
+        }
+    }
+
+    /// Vuelca el estado actual como texto plano estilo Rosetta (`.`/`O`).
+    ///
+    /// # Retorna
+    /// Una cadena con una fila por línea. `load_rle` no lee este formato.
+    pub(crate) fn to_plaintext(&self) -> String {
+        let mut out = String::with_capacity(((self.x_cells + 1) * self.y_cells) as usize);
+        for y in 0..self.y_cells {
+            for x in 0..self.x_cells {
+                let idx = (x + y * self.x_cells) as usize;
+                out.push(if self.cells[idx].alive { 'O' } else { '.' });
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Hashea unas coordenadas enteras y una semilla en un valor en `[0.0, 1.0]`.
+///
+/// # Parámetros
+/// - `x`, `y`: Coordenadas de la retícula.
+/// - `seed`: Semilla del campo de ruido.
+///
+/// # Retorna
+/// Un valor pseudoaleatorio pero determinista en el rango `[0.0, 1.0]`.
+fn hash01(x: i64, y: i64, seed: u32) -> f64 {
+    let mut h = (x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    h ^= (y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    h ^= seed as u64;
+    h = h.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+    h ^= h >> 33;
+    (h as f64) / (u64::MAX as f64)
+}
+
+/// Evalúa ruido de valor 2D con interpolación bilineal suavizada.
+///
+/// # Parámetros
+/// - `x`, `y`: Punto de muestreo (ya escalado por `scale`).
+/// - `seed`: Semilla del campo de ruido.
+///
+/// # Retorna
+/// El valor del ruido en el punto dado, en el rango `[0.0, 1.0]`.
+fn value_noise(x: f64, y: f64, seed: u32) -> f64 {
+    let x0 = x.floor() as i64;
+    let y0 = y.floor() as i64;
+    let (fx, fy) = (x - x0 as f64, y - y0 as f64);
+
+    // Curva de suavizado (smoothstep) para evitar artefactos lineales.
+    let sx = fx * fx * (3.0 - 2.0 * fx);
+    let sy = fy * fy * (3.0 - 2.0 * fy);
+
+    let n00 = hash01(x0, y0, seed);
+    let n10 = hash01(x0 + 1, y0, seed);
+    let n01 = hash01(x0, y0 + 1, seed);
+    let n11 = hash01(x0 + 1, y0 + 1, seed);
+
+    let ix0 = n00 + (n10 - n00) * sx;
+    let ix1 = n01 + (n11 - n01) * sx;
+    ix0 + (ix1 - ix0) * sy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cuenta las células vivas de una cuadrícula.
+    fn alive(grid: &Grid) -> usize {
+        grid.cells.iter().filter(|c| c.alive).count()
+    }
+
+    #[test]
+    fn load_rle_coloca_un_blinker_centrado() {
+        let mut grid = Grid::new(5, 5, 1.0, false);
+        grid.load_rle("x = 3, y = 1, rule = B3/S23\n3o!");
+
+        // Tres células vivas centradas horizontalmente en la fila central.
+        assert_eq!(alive(&grid), 3);
+        for x in 1..=3 {
+            assert!(grid.cells[x + 2 * 5].alive);
+        }
+    }
+
+    #[test]
+    fn load_rle_ignora_comentarios_y_filas() {
+        let mut grid = Grid::new(5, 5, 1.0, false);
+        // Dos filas: una célula arriba a la derecha y otra debajo a la izquierda.
+        grid.load_rle("#C comentario\nx = 2, y = 2\nbo$o!");
+        assert_eq!(alive(&grid), 2);
+    }
+
+    #[test]
+    fn to_plaintext_refleja_el_estado() {
+        let mut grid = Grid::new(3, 3, 1.0, false);
+        grid.load_rle("o!");
+
+        let dump = grid.to_plaintext();
+        assert_eq!(dump.lines().count(), 3);
+        // La única célula viva queda centrada en la cuadrícula 3×3.
+        assert_eq!(dump.lines().nth(1).unwrap(), ".O.");
+        assert_eq!(dump.matches('O').count(), 1);
+    }
 }
@@ -1,6 +1,10 @@
 // Importa las funciones y estructuras necesarias desde los módulos locales y bibliotecas externas
 use crate::app::{run_loop, App};
 use crate::celulas::LifeCell;
+use crate::cli::Cli;
+use crate::grid::CELL_SPACING;
+use crate::ruleset::Ruleset;
+use clap::Parser;
 use opengl_graphics::GlGraphics;
 use piston_window::*;
 
@@ -8,6 +12,8 @@ use piston_window::*;
 mod app;
 mod grid;
 mod celulas;
+mod ruleset;
+mod cli;
 
 // Función para contar el número de células vivas en un vector de LifeCell
 fn get_num_alive(cells: &Vec<LifeCell>) -> usize {
@@ -19,12 +25,21 @@ fn get_num_alive(cells: &Vec<LifeCell>) -> usize {
 }
 
 fn main() {
+    // Lee la configuración desde la línea de comandos
+    let cli = Cli::parse();
+
     // Define la versión de OpenGL a utilizar
     let opengl = OpenGL::V4_1;
 
-    // Crea una ventana de Piston con el título "RGoL" y tamaño 800x600 píxeles
+    // Deriva el tamaño de la ventana del número de células y su paso en píxeles,
+    // de modo que toda la cuadrícula quede visible para cualquier --width/--height.
+    let stride = cli.cell_size + CELL_SPACING;
+    let win_w = (cli.width as f64 * stride) as u32;
+    let win_h = (cli.height as f64 * stride) as u32;
+
+    // Crea una ventana de Piston con el título "RGoL" ajustada a la cuadrícula.
     // Usa la versión de OpenGL definida y configura la ventana para salir al presionar 'Esc'
-    let mut window: PistonWindow = WindowSettings::new("RGoL", [800, 600])
+    let mut window: PistonWindow = WindowSettings::new("RGoL", [win_w, win_h])
         .graphics_api(opengl)
         .exit_on_esc(true)
         .build()
@@ -33,31 +48,44 @@ fn main() {
     // Inicializa un objeto GlGraphics con la configuración de OpenGL
     let gl = GlGraphics::new(opengl);
 
-    // Define la cantidad de células en el eje x e y (400x400 células)
-    let x_cells = 400;
-    let y_cells = 400;
-    
     // Crea una nueva instancia de App con las dimensiones de células y la configuración gráfica
-    let mut app = App::new(gl, x_cells, y_cells);
-    
-    // Define la probabilidad de que una célula esté viva al azar (50%)
-    let live_prob: f64 = 0.50;
-    
-    // Imprime el número de células vivas antes de la randomización
-    println!(
-        "Num alive before randomize: {}",
-        get_num_alive(&app.grid.cells)
-    );
-    
-    // Randomiza las células en la cuadrícula según la probabilidad definida
-    app.grid.randomize(live_prob);
-    
-    // Imprime el número de células vivas después de la randomización
-    println!(
-        "Num alive after randomize: {}",
-        get_num_alive(&app.grid.cells)
-    );
-    
+    let mut app = App::new(gl, cli.width, cli.height, cli.cell_size, cli.wrap);
+
+    // Aplica la regla B/S solicitada a la cuadrícula
+    app.grid.ruleset = Ruleset::parse(&cli.rule).expect("regla B/S inválida");
+
+    // Siembra la cuadrícula: desde un archivo RLE si se indicó, o aleatoriamente
+    if let Some(pattern) = cli.pattern {
+        let text = std::fs::read_to_string(&pattern).expect("no se pudo leer el patrón");
+        app.grid.load_rle(&text);
+    } else {
+        // Para reproducibilidad, resiembra el RNG cuando se pasa --seed
+        if let Some(seed) = cli.seed {
+            app.grid.reseed(seed);
+        }
+
+        // Imprime el número de células vivas antes de la randomización
+        println!(
+            "Num alive before randomize: {}",
+            get_num_alive(&app.grid.cells)
+        );
+
+        // Selecciona el modo de siembra: ruido de valor coherente o uniforme.
+        if cli.noise {
+            let seed = cli.seed.unwrap_or(0) as u32;
+            app.grid
+                .seed_noise(cli.noise_scale, cli.noise_threshold, seed);
+        } else {
+            app.grid.randomize(cli.density);
+        }
+
+        // Imprime el número de células vivas después de la randomización
+        println!(
+            "Num alive after randomize: {}",
+            get_num_alive(&app.grid.cells)
+        );
+    }
+
     // Llama a la función run_loop para iniciar el bucle principal del juego,
     // actualizando y renderizando el estado del juego en la ventana
     run_loop(&mut app, &mut window);
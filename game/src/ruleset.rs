@@ -0,0 +1,107 @@
+/// Conjunto de reglas de un autómata celular en notación `B/S`.
+///
+/// Cada arreglo está indexado por el número de vecinos vivos (de `0` a `8`):
+/// `birth[n]` indica si una célula muerta nace con `n` vecinos vivos y
+/// `survive[n]` indica si una célula viva sobrevive con `n` vecinos vivos.
+pub struct Ruleset {
+    /// Nacimientos según el número de vecinos vivos.
+    pub birth: [bool; 9],
+    /// Supervivencias según el número de vecinos vivos.
+    pub survive: [bool; 9],
+}
+
+impl Ruleset {
+    /// Construye un conjunto de reglas a partir de una cadena `B3/S23`.
+    ///
+    /// # Parámetros
+    /// - `text`: La regla en notación estándar, por ejemplo `"B36/S23"` (HighLife)
+    ///   o `"B2/S"` (Seeds).
+    ///
+    /// # Retorna
+    /// El `Ruleset` correspondiente, o un mensaje de error si la cadena no sigue
+    /// el formato esperado.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut birth = [false; 9];
+        let mut survive = [false; 9];
+
+        let (mut saw_b, mut saw_s) = (false, false);
+        for part in text.split('/') {
+            let part = part.trim();
+            if part.is_empty() {
+                return Err(format!("segmento de regla vacío en: '{}'", text));
+            }
+
+            // Separa el prefijo por el primer carácter (no por el primer byte),
+            // para no romper en segmentos vacíos o no ASCII.
+            let mut chars = part.chars();
+            let prefix = chars.next().unwrap();
+            let digits = chars.as_str();
+            let target = match prefix {
+                'B' | 'b' => {
+                    saw_b = true;
+                    &mut birth
+                }
+                'S' | 's' => {
+                    saw_s = true;
+                    &mut survive
+                }
+                _ => return Err(format!("prefijo de regla desconocido: '{}'", part)),
+            };
+
+            for c in digits.chars() {
+                let n = c
+                    .to_digit(9)
+                    .ok_or_else(|| format!("dígito de regla inválido: '{}'", c))?;
+                target[n as usize] = true;
+            }
+        }
+
+        if !saw_b || !saw_s {
+            return Err(format!("regla incompleta: '{}'", text));
+        }
+
+        Ok(Self { birth, survive })
+    }
+}
+
+impl Default for Ruleset {
+    /// Devuelve las reglas clásicas de Conway (`B3/S23`).
+    fn default() -> Self {
+        Self::parse("B3/S23").expect("la regla de Conway siempre es válida")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsea_conway() {
+        let rule = Ruleset::parse("B3/S23").unwrap();
+        assert!(rule.birth[3]);
+        assert!(!rule.birth[2]);
+        assert!(rule.survive[2] && rule.survive[3]);
+        assert!(!rule.survive[1]);
+    }
+
+    #[test]
+    fn parsea_highlife_y_seeds() {
+        let highlife = Ruleset::parse("B36/S23").unwrap();
+        assert!(highlife.birth[3] && highlife.birth[6]);
+
+        // Seeds no tiene supervivencias: el segmento `S` queda vacío.
+        let seeds = Ruleset::parse("B2/S").unwrap();
+        assert!(seeds.birth[2]);
+        assert!(seeds.survive.iter().all(|s| !s));
+    }
+
+    #[test]
+    fn rechaza_reglas_invalidas() {
+        // Segmento vacío (cadena con barra final) en lugar de entrar en pánico.
+        assert!(Ruleset::parse("B3/").is_err());
+        // Falta uno de los prefijos.
+        assert!(Ruleset::parse("B3").is_err());
+        // Dígito fuera de rango.
+        assert!(Ruleset::parse("B9/S23").is_err());
+    }
+}